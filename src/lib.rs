@@ -1,65 +1,65 @@
-use std::ops::{Add,Sub};
+use std::ops::{Add,Sub,Mul,Div,Neg,AddAssign,SubAssign,MulAssign,DivAssign};
 use std::fmt;
 
-/// Ring360 is a tuple struct encapsulating an f64
+/// Ring<N> is a tuple struct encapsulating an f64 that wraps modularly around a ring
+/// of N units, e.g. 360 for degrees, 24 for clock hours or 60 for minutes/seconds.
+/// The base-agnostic modular arithmetic, rotation counting and shortest-angle logic
+/// lives here; `Ring360` below is a degree-specific alias with its own extensions.
 #[derive(Debug, Clone, Copy)]
-pub struct Ring360(pub f64);
+pub struct Ring<const N: u32>(pub f64);
 
-/// Methods
-impl Ring360 {
+/// Ring360 is the original degree-based ring, kept as a type alias over Ring<360>.
+/// Note that Rust does not allow a type alias of a generic tuple struct to be called
+/// as a constructor, so build values with the bare `Ring(value)` (type inference will
+/// pin N from context) rather than `Ring360(value)`.
+pub type Ring360 = Ring<360>;
 
-  /// The base is 360.0. All degree values will be modulated by this number
-  pub const BASE:f64 = 360.0;
+/// Base-agnostic methods shared by every Ring<N>
+impl<const N: u32> Ring<N> {
 
-	/// Alternative constructor if the source degree uses the ±180º GIS system
-  /// This will not affect the degree conversion, but only the initial rotations
-  /// value, e.g. (-20).
-  pub fn from_gis(lng180: f64) -> Ring360 {
-    if lng180 < 0.0 {
-      Ring360(Self::BASE + lng180)
-    } else {
-      Ring360(lng180)
-    }
-  }
+  /// The modular base for this ring, e.g. 360.0 for Ring360 or 24.0 for a clock ring
+  pub const BASE:f64 = N as f64;
 
-  /// Degrees as 64-bit floats on the 0º to 360º scale around a circle
+  /// The wrapped value as a 64-bit float on the 0 to BASE scale around the ring
   /// Use value() for the intrinsic value that may extend beyond this range
-  pub fn degrees(&self) -> f64 {
-    let deg_val = self.0 % Self::BASE;
-    if deg_val < 0.0 { 
-      Self::BASE - (0.0 - deg_val)
+  pub fn units(&self) -> f64 {
+    let unit_val = self.0 % Self::BASE;
+    if unit_val < 0.0 {
+      Self::BASE - (0.0 - unit_val)
     } else {
-      deg_val
+      unit_val
     }
   }
 
-	/// Alias for for .degrees(), but is the default f64 conversion
+	/// Alias for units(), the default f64 conversion
   pub fn to_f64(&self) -> f64 {
-    self.degrees()
+    self.units()
   }
 
-	/// Convert the internal 0-360º scale back to the -180º to +180º GIS scale
-	pub fn to_gis(&self) -> f64 {
-    if self.degrees() <= Self::half_turn() {
-      self.degrees()
-    } else {
-      self.degrees() - Self::BASE
-    }
-  }
-
-  /// Get the number of rotations. If the total is less than base of 360
+  /// Get the number of rotations. If the total is less than the base
   pub fn rotations(&self) -> i64 {
     (self.0 / Self::BASE).floor() as i64
   }
 
   /// Get the intrinsic raw value as a decimal fraction of rotations
-  /// e.g. 180.0 translates to 0.5 and 450.0 to 1.25
+  /// e.g. half the base translates to 0.5 and 1.25 bases to 1.25
   pub fn progress(&self) -> f64 {
     self.0 / Self::BASE
   }
 
-	/// Returns the raw internal f64 value on a 0-360º scale. 
-	/// Values under 0 or over 360 represent negative or positive rotations
+  /// Construct a Ring<N> from a fraction of a full turn, e.g. 0.25 -> a quarter of BASE
+  pub fn from_turns(fraction: f64) -> Ring<N> {
+    Ring(fraction * Self::BASE)
+  }
+
+  /// Get the normalized fraction of a turn in the range [0.0, 1.0), derived from units()
+  /// Unlike progress(), this discards whole rotations
+  pub fn turn_fraction(&self) -> f64 {
+    self.units() / Self::BASE
+  }
+
+	/// Returns the raw internal f64 value on a 0-BASE scale.
+	/// Values under 0 or over BASE represent negative or positive rotations
   pub fn value(&self) -> f64 {
     self.0
   }
@@ -72,29 +72,29 @@ impl Ring360 {
     0.0 - Self::BASE / 2.0
   }
 
-  /// Return a simple tuple pair with the 
-  /// 360º degree value and the number of rotations (turns)
+  /// Return a simple tuple pair with the
+  /// 0-BASE value and the number of rotations (turns)
   pub fn as_tuple(&self) -> (f64, i64) {
-    (self.degrees(), self.rotations())
+    (self.units(), self.rotations())
   }
 
-  /// Multiply a Ring360 value by a normal f64 value
+  /// Multiply a Ring<N> value by a normal f64 value
   pub fn multiply(mut self, multiple: f64) -> Self {
     self.0 *= multiple;
     self
   }
 
-  /// Divide a Ring360 by a normal f64 value
+  /// Divide a Ring<N> by a normal f64 value
   pub fn divide(mut self, divisor: f64) -> Self {
     self.0 /= divisor;
     self
   }
 
-  /// Calculate the shortest distance in degrees between a Ring360 value
-  /// and a 64-bit float representing a degree
-	/// A positive value represents clockwise movement between the first and second longitude
+  /// Calculate the shortest distance in ring units between a Ring<N> value
+  /// and a 64-bit float representing a position on the same ring
+	/// A positive value represents clockwise movement between the first and second position
   pub fn angle_f64(&self, other_value: f64) -> f64 {
-    let mut diff = (other_value % Self::BASE) - self.degrees();
+    let mut diff = (other_value % Self::BASE) - self.units();
     if diff < Self::minus_half_turn() {
         diff += Self::BASE;
     } else if diff > Self::half_turn() {
@@ -103,8 +103,9 @@ impl Ring360 {
     diff
   }
 
-  /// Calculate the absolute angle with another 64-bit float in the 0 to 360º system
-  /// only in a clockwise direction with the 180º to 359.999º representing half to a full turn
+  /// Calculate the absolute angle with another 64-bit float on the same ring
+  /// only in a clockwise direction, with the upper half of the base representing
+  /// half to a full turn
   pub fn angle_f64_abs(&self, other_value: f64) -> f64 {
    let relative_value = self.angle_f64(other_value);
    if relative_value < 0.0 {
@@ -114,17 +115,50 @@ impl Ring360 {
    }
   }
 
-  /// Calculate the shortest distance in degrees between 
-  /// two a Ring360 values
-  pub fn angle(&self, other_value: Ring360) -> f64 {
-    self.angle_f64(other_value.degrees())
+  /// Calculate the shortest distance in ring units between
+  /// two Ring<N> values
+  pub fn angle(&self, other_value: Ring<N>) -> f64 {
+    self.angle_f64(other_value.units())
   }
-  /// Calculate the absolute angle with another Ring360 degree
-  /// only in a clockwise direction with the 180º to 359.999º representing half to a full turn
-  pub fn angle_abs(&self, other_value: Ring360) -> f64 {
-    self.angle_f64_abs(other_value.degrees())
+  /// Calculate the absolute angle with another Ring<N> value
+  /// only in a clockwise direction, with the upper half of the base representing
+  /// half to a full turn
+  pub fn angle_abs(&self, other_value: Ring<N>) -> f64 {
+    self.angle_f64_abs(other_value.units())
    }
 
+}
+
+/// Degree-specific methods and constructors that only make sense on the 360º ring
+impl Ring360 {
+
+	/// Alternative constructor if the source degree uses the ±180º GIS system
+  /// This will not affect the degree conversion, but only the initial rotations
+  /// value, e.g. (-20).
+  pub fn from_gis(lng180: f64) -> Ring360 {
+    if lng180 < 0.0 {
+      Ring(Self::BASE + lng180)
+    } else {
+      Ring(lng180)
+    }
+  }
+
+  /// Degrees as 64-bit floats on the 0º to 360º scale around a circle
+  /// Alias for units(), kept for the degree-specific API
+  /// Use value() for the intrinsic value that may extend beyond this range
+  pub fn degrees(&self) -> f64 {
+    self.units()
+  }
+
+	/// Convert the internal 0-360º scale back to the -180º to +180º GIS scale
+	pub fn to_gis(&self) -> f64 {
+    if self.degrees() <= Self::half_turn() {
+      self.degrees()
+    } else {
+      self.degrees() - Self::BASE
+    }
+  }
+
 	/// Convert to radians for use with cos(), sin(), tan(), atan() etc.
   pub fn to_radians(&self) -> f64 {
     self.degrees().to_radians()
@@ -160,48 +194,314 @@ impl Ring360 {
     self.to_radians().atan()
   }
 
+  /// Split the degree value into a sexagesimal (degrees, arc-minutes, arc-seconds) triple
+  /// 1º = 60′ and 1′ = 60″
+  pub fn to_dms(&self) -> (i32, u32, f64) {
+    let degrees = self.degrees();
+    let whole_degrees = degrees.trunc() as i32;
+    let minutes_f64 = (degrees - whole_degrees as f64) * 60.0;
+    let whole_minutes = minutes_f64.trunc() as u32;
+    let seconds = (minutes_f64 - whole_minutes as f64) * 60.0;
+    Self::normalize_dms(whole_degrees, whole_minutes, seconds, Self::BASE as i32)
+  }
+
+  /// Render the degree value as a sexagesimal string, e.g. 285° 12′ 47.4″
+  pub fn to_dms_string(&self) -> String {
+    let (degrees, minutes, seconds) = self.to_dms();
+    format!("{}° {}′ {:.1}″", degrees, minutes, seconds)
+  }
+
+  /// Split the degree value into an hour-angle (hours, minutes, seconds) triple
+  /// following the astronomical convention where 360º maps to 24 hours (15º = 1 hour)
+  pub fn to_hms(&self) -> (u32, u32, f64) {
+    let decimal_hours = self.degrees() / 15.0;
+    let whole_hours = decimal_hours.trunc() as u32;
+    let minutes_f64 = (decimal_hours - whole_hours as f64) * 60.0;
+    let whole_minutes = minutes_f64.trunc() as u32;
+    let seconds = (minutes_f64 - whole_minutes as f64) * 60.0;
+    let (carried_hours, minutes, seconds) = Self::normalize_dms(whole_hours as i32, whole_minutes, seconds, 24);
+    (carried_hours as u32, minutes, seconds)
+  }
+
+  /// Build a Ring360 from an hour-angle (hours, minutes, seconds) triple,
+  /// the inverse of to_hms()
+  pub fn from_hms(hours: u32, minutes: u32, seconds: f64) -> Ring360 {
+    let decimal_hours = hours as f64 + (minutes as f64 / 60.0) + (seconds / 3600.0);
+    Ring(decimal_hours * 15.0)
+  }
+
+  /// Parse a sexagesimal string, e.g. "285° 12′ 47.4″" or "-20° 0′ 0″",
+  /// folding negative degrees through the normal 0-360º normalization
+  pub fn from_dms_str(source: &str) -> Option<Ring360> {
+    let parts: Vec<f64> = source
+      .split(|c: char| !c.is_ascii_digit() && c != '.' && c != '-')
+      .filter(|part| !part.is_empty())
+      .map(|part| part.parse::<f64>())
+      .collect::<Result<Vec<f64>, _>>()
+      .ok()?;
+    let degrees = *parts.first()?;
+    let minutes = *parts.get(1).unwrap_or(&0.0);
+    let seconds = *parts.get(2).unwrap_or(&0.0);
+    let sign = if degrees < 0.0 { -1.0 } else { 1.0 };
+    let total_degrees = sign * (degrees.abs() + minutes / 60.0 + seconds / 3600.0);
+    Some(Ring(total_degrees))
+  }
+
+  /// Carry seconds/minutes rounded up to 60 into the next unit, keeping a DMS/HMS triple valid,
+  /// then wrap the degree/hour component back through 0..base in case the carry overflowed it
+  /// (e.g. 359.99999999º rounding up to 360º 0′ 0″ should wrap to 0º 0′ 0″)
+  fn normalize_dms(mut degrees: i32, mut minutes: u32, mut seconds: f64, base: i32) -> (i32, u32, f64) {
+    seconds = (seconds * 10.0).round() / 10.0;
+    if seconds >= 60.0 {
+      seconds -= 60.0;
+      minutes += 1;
+    }
+    if minutes >= 60 {
+      minutes -= 60;
+      degrees += 1;
+    }
+    degrees = degrees.rem_euclid(base);
+    (degrees, minutes, seconds)
+  }
+
+  /// Interpolate along the shortest signed arc from self towards other by fraction t,
+  /// e.g. interpolating from 350º to 10º at t=0.5 yields 0º rather than 180º
+  pub fn lerp(&self, other: Ring360, t: f64) -> Ring360 {
+    Ring(self.value() + t * self.angle(other))
+  }
+
+  /// Interpolate along the explicitly longer arc from self towards other by fraction t
+  pub fn lerp_long(&self, other: Ring360, t: f64) -> Ring360 {
+    let short_angle = self.angle(other);
+    let long_angle = if short_angle >= 0.0 {
+      short_angle - Self::BASE
+    } else {
+      short_angle + Self::BASE
+    };
+    Ring(self.value() + t * long_angle)
+  }
+
+  /// Interpolate clockwise from self towards other by fraction t, regardless of which arc is shorter
+  pub fn lerp_cw(&self, other: Ring360, t: f64) -> Ring360 {
+    Ring(self.value() + t * self.angle_abs(other))
+  }
+
+  /// Interpolate anticlockwise from self towards other by fraction t, regardless of which arc is shorter
+  pub fn lerp_ccw(&self, other: Ring360, t: f64) -> Ring360 {
+    Ring(self.value() + t * (self.angle_abs(other) - Self::BASE))
+  }
+
+  /// Compare two values via the wrapped shortest angle rather than their raw degrees,
+  /// correctly treating 359.9999º and 0.0001º as near-equal across the seam
+  pub fn approx_eq(&self, other: Ring360, epsilon: f64) -> bool {
+    self.angle(other).abs() <= epsilon
+  }
+
+}
+
+/// Implement + (addition) operator with two Ring<N> values
+impl<const N: u32> Add for Ring<N> {
+
+  type Output = Ring<N>;
+
+  /// Implement + for Ring<N>
+  fn add(mut self, other: Ring<N>) -> Self {
+    self.0 += other.value();
+    self
+  }
+}
+
+/// Implement - (subtraction) operator with two Ring<N> values
+impl<const N: u32> Sub for Ring<N> {
+
+  type Output = Ring<N>;
+
+  /// Implement - for Ring<N>
+  fn sub(mut self, other: Ring<N>) -> Self {
+    self.0 -= other.value();
+    self
+  }
 }
 
-/// Implement + (addition) operator with two Ring30 values
-impl Add for Ring360 {
+/// Implement + for borrowed Ring<N> operands
+impl<const N: u32> Add<&Ring<N>> for Ring<N> {
 
-  type Output = Ring360;
+  type Output = Ring<N>;
 
-  /// Implement + for Ring360
-  fn add(mut self, other: Ring360) -> Self {
+  fn add(mut self, other: &Ring<N>) -> Self {
     self.0 += other.value();
     self
   }
 }
 
-/// Implement - (subtraction) operator with two Ring30 values
-impl Sub for Ring360 {
+/// Implement - for borrowed Ring<N> operands
+impl<const N: u32> Sub<&Ring<N>> for Ring<N> {
 
-  type Output = Ring360;
+  type Output = Ring<N>;
 
-  /// Implement - for Ring360
-  fn sub(mut self, other: Ring360) -> Self {
+  fn sub(mut self, other: &Ring<N>) -> Self {
     self.0 -= other.value();
     self
   }
 }
 
-/// Implement default display for Ring360 as the degree value
-impl fmt::Display for Ring360 {
-  /// By default display the circular degree value
+/// Implement + for a borrowed Ring<N> left-hand operand
+impl<const N: u32> Add<Ring<N>> for &Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn add(self, other: Ring<N>) -> Ring<N> {
+    Ring(self.0 + other.value())
+  }
+}
+
+/// Implement - for a borrowed Ring<N> left-hand operand
+impl<const N: u32> Sub<Ring<N>> for &Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn sub(self, other: Ring<N>) -> Ring<N> {
+    Ring(self.0 - other.value())
+  }
+}
+
+/// Implement + between two borrowed Ring<N> operands
+impl<const N: u32> Add<&Ring<N>> for &Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn add(self, other: &Ring<N>) -> Ring<N> {
+    Ring(self.0 + other.value())
+  }
+}
+
+/// Implement - between two borrowed Ring<N> operands
+impl<const N: u32> Sub<&Ring<N>> for &Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn sub(self, other: &Ring<N>) -> Ring<N> {
+    Ring(self.0 - other.value())
+  }
+}
+
+/// Implement * (multiplication) with a plain f64 scalar, delegating to multiply()
+impl<const N: u32> Mul<f64> for Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn mul(self, multiple: f64) -> Self {
+    self.multiply(multiple)
+  }
+}
+
+/// Implement / (division) with a plain f64 scalar, delegating to divide()
+impl<const N: u32> Div<f64> for Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn div(self, divisor: f64) -> Self {
+    self.divide(divisor)
+  }
+}
+
+/// Implement unary - (negation) of the raw value, so whole rotations are negated
+/// rather than normalized away, e.g. 450.0 becomes -450.0
+impl<const N: u32> Neg for Ring<N> {
+
+  type Output = Ring<N>;
+
+  fn neg(self) -> Self {
+    Ring(-self.0)
+  }
+}
+
+/// Implement += for two Ring<N> values
+impl<const N: u32> AddAssign for Ring<N> {
+  fn add_assign(&mut self, other: Ring<N>) {
+    self.0 += other.value();
+  }
+}
+
+/// Implement -= for two Ring<N> values
+impl<const N: u32> SubAssign for Ring<N> {
+  fn sub_assign(&mut self, other: Ring<N>) {
+    self.0 -= other.value();
+  }
+}
+
+/// Implement *= with a plain f64 scalar
+impl<const N: u32> MulAssign<f64> for Ring<N> {
+  fn mul_assign(&mut self, multiple: f64) {
+    self.0 *= multiple;
+  }
+}
+
+/// Implement /= with a plain f64 scalar
+impl<const N: u32> DivAssign<f64> for Ring<N> {
+  fn div_assign(&mut self, divisor: f64) {
+    self.0 /= divisor;
+  }
+}
+
+/// Implement default display for Ring<N> as the normalized unit value
+impl<const N: u32> fmt::Display for Ring<N> {
+  /// By default display the circular unit value
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "{}", self.degrees())
+    write!(f, "{}", self.units())
   }
 }
 
-/// trait to convert normal float values to a Ring360 value 
+/// Two ring values are equal when their normalized positions match, so a value
+/// one full turn apart from another compares equal on the circle
+impl<const N: u32> PartialEq for Ring<N> {
+  fn eq(&self, other: &Self) -> bool {
+    self.units() == other.units()
+  }
+}
+
+/// Order ring values by their normalized position on the circle
+impl<const N: u32> PartialOrd for Ring<N> {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    self.units().partial_cmp(&other.units())
+  }
+}
+
+/// Serialize a Ring<N> as its normalized unit value rather than the raw rotation-bearing f64
+#[cfg(feature = "serde")]
+impl<const N: u32> serde::Serialize for Ring<N> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_f64(self.units())
+  }
+}
+
+/// Deserialize a Ring<N> from a plain f64, the same normalized unit value it serializes as
+#[cfg(feature = "serde")]
+impl<'de, const N: u32> serde::Deserialize<'de> for Ring<N> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let value = f64::deserialize(deserializer)?;
+    Ok(Ring(value))
+  }
+}
+
+/// trait to convert normal float values to a Ring360 value
 /// and to apply a simple mod_360() returning a 64-bit float
 pub trait ToRing360 {
 	fn to_360(&self) -> Ring360;
   fn to_360_gis(&self) -> Ring360;
+  fn to_360_turn(&self) -> Ring360;
 	fn mod_360(&self) -> Self;
   fn angle_360(&self, other_value: f64) -> Self;
   fn angle_360_abs(&self, other_value: f64) -> Self;
+  fn to_dms(&self) -> (i32, u32, f64);
+  fn to_dms_string(&self) -> String;
+  fn to_hms(&self) -> (u32, u32, f64);
 }
 
 /// Implement casting methods for f64
@@ -209,7 +509,7 @@ impl ToRing360 for f64 {
 
   /// Convert to a Ring360 struct
 	fn to_360(&self) -> Ring360 {
-    Ring360(*self)
+    Ring(*self)
   }
 
   /// Convert to GIS ±180 representation
@@ -217,21 +517,41 @@ impl ToRing360 for f64 {
     Ring360::from_gis(*self)
   }
 
+  /// Convert a fraction of a full turn to a Ring360, e.g. 0.25 -> 90º
+  fn to_360_turn(&self) -> Ring360 {
+    Ring360::from_turns(*self)
+  }
+
   /// Convert a 64-bit float directly to the 0 to 360º system
 	fn mod_360(&self) -> f64 {
-    Ring360(*self).degrees()
+    Ring(*self).degrees()
   }
 
   /// Calculate the shortest relative angle with another 64-bit float in the 0 to 360º system
   /// with negative values indicating an anticlockwise direction
   fn angle_360(&self, other_value: f64) -> f64 {
-    Ring360(*self).angle_f64(other_value)
+    Ring::<360>(*self).angle_f64(other_value)
   }
 
   /// Calculate the absolute angle with another 64-bit float in the 0 to 360º system
   /// only in a clockwise direction with the 180º to 359.999º representing half to a full turn
   fn angle_360_abs(&self, other_value: f64) -> f64 {
-    Ring360(*self).angle_f64_abs(other_value)
+    Ring::<360>(*self).angle_f64_abs(other_value)
+  }
+
+  /// Split the degree value into a sexagesimal (degrees, arc-minutes, arc-seconds) triple
+  fn to_dms(&self) -> (i32, u32, f64) {
+    Ring(*self).to_dms()
+  }
+
+  /// Render the degree value as a sexagesimal string, e.g. 285° 12′ 47.4″
+  fn to_dms_string(&self) -> String {
+    Ring(*self).to_dms_string()
+  }
+
+  /// Split the degree value into an hour-angle (hours, minutes, seconds) triple
+  fn to_hms(&self) -> (u32, u32, f64) {
+    Ring(*self).to_hms()
   }
 
 }
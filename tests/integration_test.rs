@@ -7,8 +7,8 @@ fn test_operations() {
     let v1 = 271.893635;
     let v2 = 134.635893;
     let expected_result_f64 = (v1 + v2) % Ring360::BASE;
-    let d1 = Ring360(v1);
-    let d2 = Ring360(v2);
+    let d1: Ring360 = Ring(v1);
+    let d2: Ring360 = Ring(v2);
     let d3 = d1 + d2;
     assert_eq!(d3.to_f64(), expected_result_f64);
 
@@ -20,7 +20,7 @@ fn test_operations() {
 #[test]
 fn test_multiply() {
     let v2 = 134.635893;
-    let d2 = Ring360(v2);
+    let d2: Ring360 = Ring(v2);
     let m1 = 4.0;
     let d5 = d2.multiply(m1);
     let expected_result_f64 = (v2 * m1) % Ring360::BASE;
@@ -100,7 +100,7 @@ fn test_multiplication() {
     let v1 = 271.893635;
     let v2 = 4.0;
     
-    let d1 = Ring360(v1);
+    let d1: Ring360 = Ring(v1);
     
     let expected_result_f64 = (v1 * v2) % Ring360::BASE;
 
@@ -182,7 +182,7 @@ fn test_from_gis_constructor() {
     let gis_180_value = -90.0;
 
     let expected_360_value = 270.0;
-    let value_with_default_constructor = Ring360(gis_180_value);
+    let value_with_default_constructor: Ring360 = Ring(gis_180_value);
     let value_with_gis_constructor = Ring360::from_gis(gis_180_value);
 
     assert_eq!(value_with_default_constructor.degrees(), expected_360_value);
@@ -203,7 +203,7 @@ fn test_mod_360() {
 
 #[test]
 fn test_to_radians() {
-    
+
     let deg_val = 77.2483;
     let lng = deg_val.to_360();
     let radian_val = deg_val / 180.0 * std::f64::consts::PI;
@@ -211,6 +211,211 @@ fn test_to_radians() {
     assert_eq!(lng.to_radians(), radian_val);
 
     assert_eq!(lng.cos(), radian_val.cos());
-    
+
+}
+
+#[test]
+fn test_to_dms() {
+
+    let lng: Ring360 = Ring(285.213166667);
+
+    let (degrees, minutes, seconds) = lng.to_dms();
+
+    assert_eq!(degrees, 285);
+    assert_eq!(minutes, 12);
+    assert!((seconds - 47.4).abs() < 0.1);
+
+    assert_eq!(lng.to_dms_string(), "285° 12′ 47.4″");
+
+    // a negative degree value should fold through the normal 0-360º normalization first
+    let negative_lng: Ring360 = Ring(-75.0);
+    let (degrees, _, _) = negative_lng.to_dms();
+    assert_eq!(degrees, 285);
+
+    // a rounding carry that overflows 360º should wrap back to 0º rather than returning 360º
+    let almost_full_turn: Ring360 = Ring(359.99999999);
+    assert_eq!(almost_full_turn.to_dms(), (0, 0, 0.0));
+    assert_eq!(almost_full_turn.to_dms_string(), "0° 0′ 0.0″");
+}
+
+#[test]
+fn test_from_dms_str() {
+
+    let parsed = Ring360::from_dms_str("285° 12′ 47.4″").unwrap();
+    assert!((parsed.degrees() - 285.213166667).abs() < 0.0001);
+
+    let parsed_negative = Ring360::from_dms_str("-75° 0′ 0″").unwrap();
+    assert_eq!(parsed_negative.degrees(), 285.0);
+
+    assert!(Ring360::from_dms_str("not a dms string").is_none());
+}
+
+#[test]
+fn test_to_hms_and_from_hms() {
+
+    // 15º maps to exactly 1 hour
+    let lng: Ring360 = Ring(15.0);
+    assert_eq!(lng.to_hms(), (1, 0, 0.0));
+
+    let round_tripped = Ring360::from_hms(1, 0, 0.0);
+    assert_eq!(round_tripped.degrees(), 15.0);
+
+    let right_ascension: Ring360 = Ring(285.213166667);
+    let (hours, minutes, seconds) = right_ascension.to_hms();
+    assert_eq!(hours, 19);
+    assert_eq!(minutes, 0);
+    assert!((seconds - 51.16).abs() < 0.1);
+
+    // a rounding carry that overflows 24h should wrap back to 0h rather than returning 24h
+    let almost_full_turn: Ring360 = Ring(359.99999999);
+    assert_eq!(almost_full_turn.to_hms(), (0, 0, 0.0));
+}
+
+#[test]
+fn test_from_turns_and_turn_fraction() {
+
+    let quarter_turn = Ring360::from_turns(0.25);
+    assert_eq!(quarter_turn.degrees(), 90.0);
+
+    let one_and_half_turns = Ring360::from_turns(1.5);
+    assert_eq!(one_and_half_turns.degrees(), 180.0);
+    assert_eq!(one_and_half_turns.turn_fraction(), 0.5);
+    // turn_fraction discards whole rotations, unlike progress()
+    assert_eq!(one_and_half_turns.progress(), 1.5);
+
+    let via_trait = 0.75.to_360_turn();
+    assert_eq!(via_trait.degrees(), 270.0);
+}
+
+#[test]
+// the by-ref assertions below deliberately exercise the &Ring360 operator impls,
+// which clippy otherwise flags as pointless references
+#[allow(clippy::op_ref)]
+fn test_scalar_and_ref_operators() {
+
+    let v1 = 271.893635;
+    let v2 = 134.635893;
+    let d1: Ring360 = Ring(v1);
+    let d2: Ring360 = Ring(v2);
+
+    // by-ref permutations should match the by-value result
+    let expected = d1 + d2;
+    assert_eq!((d1 + &d2).to_f64(), expected.to_f64());
+    assert_eq!((&d1 + d2).to_f64(), expected.to_f64());
+    assert_eq!((&d1 + &d2).to_f64(), expected.to_f64());
+
+    let expected_sub = d1 - d2;
+    assert_eq!((d1 - &d2).to_f64(), expected_sub.to_f64());
+    assert_eq!((&d1 - d2).to_f64(), expected_sub.to_f64());
+    assert_eq!((&d1 - &d2).to_f64(), expected_sub.to_f64());
+
+    let m = 4.0;
+    assert_eq!((d1 * m).to_f64(), d1.multiply(m).to_f64());
+    assert_eq!((d1 / m).to_f64(), d1.divide(m).to_f64());
+
+    // negation flips the sign of the raw value rather than of the normalized degrees
+    let negated = -d1;
+    assert_eq!(negated.value(), -v1);
+
+    let mut d3: Ring360 = Ring(v1);
+    d3 += d2;
+    assert_eq!(d3.to_f64(), expected.to_f64());
+
+    let mut d4: Ring360 = Ring(v1);
+    d4 -= d2;
+    assert_eq!(d4.to_f64(), expected_sub.to_f64());
+
+    let mut d5: Ring360 = Ring(v1);
+    d5 *= m;
+    assert_eq!(d5.to_f64(), d1.multiply(m).to_f64());
+
+    let mut d6: Ring360 = Ring(v1);
+    d6 /= m;
+    assert_eq!(d6.to_f64(), d1.divide(m).to_f64());
+}
+
+#[test]
+fn test_generic_ring_base() {
+
+    // a 24-unit ring, e.g. clock hours, reuses the same modular arithmetic as Ring360
+    let clock: Ring<24> = Ring(26.0);
+    assert_eq!(clock.units(), 2.0);
+    assert_eq!(clock.rotations(), 1);
+
+    let a: Ring<24> = Ring(22.0);
+    let b: Ring<24> = Ring(2.0);
+    // shortest distance wraps across midnight rather than going the long way round
+    assert_eq!(a.angle(b), 4.0);
+
+    let from_turn: Ring<24> = Ring::from_turns(0.5);
+    assert_eq!(from_turn.units(), 12.0);
+}
+
+#[test]
+fn test_lerp() {
+
+    let from: Ring360 = Ring(350.0);
+    let to: Ring360 = Ring(10.0);
+
+    // the shortest arc crosses the 0º seam rather than going the long way via 180º
+    assert_eq!(from.lerp(to, 0.5).degrees(), 0.0);
+    assert_eq!(from.lerp(to, 0.0).degrees(), 350.0);
+    assert_eq!(from.lerp(to, 1.0).degrees(), 10.0);
+
+    // the explicitly longer arc goes the other way round
+    assert_eq!(from.lerp_long(to, 0.5).degrees(), 180.0);
+
+    let a: Ring360 = Ring(10.0);
+    let b: Ring360 = Ring(350.0);
+    // forcing clockwise movement goes the long way from 10º to 350º
+    assert_eq!(a.lerp_cw(b, 0.5).degrees(), 180.0);
+    // forcing anticlockwise movement takes the short way across the seam
+    assert_eq!(a.lerp_ccw(b, 0.5).degrees(), 0.0);
+}
+
+#[test]
+fn test_equality_and_ordering() {
+
+    let one_turn: Ring360 = Ring(360.0);
+    let zero_turns: Ring360 = Ring(0.0);
+    // a value one full turn apart compares equal on the circle
+    assert_eq!(one_turn, zero_turns);
+
+    let ten_degrees: Ring360 = Ring(10.0);
+    let twenty_degrees: Ring360 = Ring(20.0);
+    assert!(ten_degrees < twenty_degrees);
+    assert!(twenty_degrees > ten_degrees);
+}
+
+#[test]
+fn test_approx_eq() {
+
+    let near_zero: Ring360 = Ring(0.0001);
+    let near_full_turn: Ring360 = Ring(359.9999);
+    // these are near-equal across the 0º/360º seam, unlike a plain degrees() comparison
+    assert!(near_zero.approx_eq(near_full_turn, 0.001));
+    assert_ne!(near_zero.degrees(), near_full_turn.degrees());
+
+    let ten_degrees: Ring360 = Ring(10.0);
+    assert!(!near_zero.approx_eq(ten_degrees, 0.001));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde_round_trip() {
+
+    // a raw value past a full rotation should serialize as its normalized units(), not 385.5
+    let past_full_turn: Ring360 = Ring(385.5);
+    let json = serde_json::to_string(&past_full_turn).unwrap();
+    assert_eq!(json, serde_json::to_string(&past_full_turn.units()).unwrap());
+
+    let deserialized: Ring360 = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.degrees(), past_full_turn.degrees());
+
+    // a value just below 0º crosses the 0º/360º seam and should round-trip to the same position
+    let across_seam: Ring360 = Ring(-0.0001);
+    let seam_json = serde_json::to_string(&across_seam).unwrap();
+    let seam_deserialized: Ring360 = serde_json::from_str(&seam_json).unwrap();
+    assert!(seam_deserialized.approx_eq(across_seam, 1e-9));
 }
 